@@ -1,22 +1,185 @@
 /// Maps 16-keys chip-8 keyboard to contemporary keyboard layout
 mod key_map {
+    use std::sync::OnceLock;
+
     /// i-th character represents key which - when pressed - is mapped to key i in chip-8 kbd
-    const MAPPING: &'static str = "x123qweasdzc4rfv";
+    const DEFAULT_MAPPING: &'static str = "x123qweasdzc4rfv";
+
+    static MAPPING: OnceLock<String> = OnceLock::new();
+
+    /// Keys `handle_input` reserves for emulator controls (quit, pause,
+    /// single-step, speed up/down); a keymap can't reuse them without making
+    /// the corresponding CHIP-8 key unreachable.
+    const RESERVED_CONTROL_KEYS: &[char] = &[',', 'p', '.', '+', '-'];
+
+    fn mapping() -> &'static str {
+        MAPPING
+            .get()
+            .map(|s| s.as_str())
+            .unwrap_or(DEFAULT_MAPPING)
+    }
+
+    /// Overrides the default mapping, e.g. from a config file. `mapping` must be
+    /// exactly 16 characters long so `map_base16_to_key`/`map_key_to_base16` stay
+    /// total over the CHIP-8 key range, and must not contain any reserved
+    /// control key.
+    pub(crate) fn set_mapping(mapping: String) -> Result<(), String> {
+        if mapping.chars().count() != 16 {
+            return Err(format!(
+                "keymap must be exactly 16 characters, got {}",
+                mapping.chars().count()
+            ));
+        }
+        if let Some(c) = mapping.chars().find(|c| RESERVED_CONTROL_KEYS.contains(c)) {
+            return Err(format!("keymap can't reuse reserved control key '{}'", c));
+        }
+        let _ = MAPPING.set(mapping);
+        Ok(())
+    }
 
     pub(crate) fn map_base16_to_key(idx: usize) -> Option<char> {
-        MAPPING.chars().nth(idx)
+        mapping().chars().nth(idx)
     }
 
     pub(crate) fn map_key_to_base16(k: char) -> Option<usize> {
-        MAPPING
+        mapping()
             .char_indices()
             .find(|(_, c)| *c == k)
             .map(|(idx, _)| idx)
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn set_mapping_rejects_wrong_length() {
+            assert!(set_mapping("short".to_string()).is_err());
+        }
+
+        #[test]
+        fn set_mapping_rejects_reserved_control_key() {
+            assert!(set_mapping("p123qweasdzc4rfv".to_string()).is_err());
+        }
+
+        #[test]
+        fn set_mapping_accepts_valid_mapping() {
+            assert!(set_mapping("0123456789abcdef".to_string()).is_ok());
+        }
+    }
+}
+
+/// Makes the CHIP-8 sound timer audible as the classic single-tone beep
+mod audio {
+    use rodio::source::Source;
+    use rodio::{OutputStream, Sink};
+    use std::time::Duration;
+
+    /// Something that can start/stop a continuous tone while `st > 0`
+    pub trait Beeper {
+        fn start(&mut self);
+        fn stop(&mut self);
+    }
+
+    /// Builds a real beeper backed by the default output device, falling back
+    /// to a silent no-op when no device is available.
+    pub fn default_beeper() -> Box<dyn Beeper> {
+        match RodioBeeper::new() {
+            Some(b) => Box::new(b),
+            None => Box::new(NullBeeper),
+        }
+    }
+
+    /// Feeds a fixed-frequency square wave to the default audio device via `rodio`
+    struct RodioBeeper {
+        // kept alive so the stream isn't dropped while the sink still plays
+        _stream: OutputStream,
+        sink: Sink,
+    }
+
+    impl RodioBeeper {
+        fn new() -> Option<Self> {
+            let (stream, handle) = OutputStream::try_default().ok()?;
+            let sink = Sink::try_new(&handle).ok()?;
+            sink.append(SquareWave::new(440.0));
+            sink.pause();
+            Some(RodioBeeper {
+                _stream: stream,
+                sink,
+            })
+        }
+    }
+
+    impl Beeper for RodioBeeper {
+        fn start(&mut self) {
+            self.sink.play();
+        }
+
+        fn stop(&mut self) {
+            self.sink.pause();
+        }
+    }
+
+    /// Used when no audio device initializes; keeps callers oblivious to the missing beep
+    struct NullBeeper;
+
+    impl Beeper for NullBeeper {
+        fn start(&mut self) {}
+        fn stop(&mut self) {}
+    }
+
+    struct SquareWave {
+        freq: f32,
+        num_sample: u32,
+    }
+
+    impl SquareWave {
+        const SAMPLE_RATE: u32 = 44100;
+
+        fn new(freq: f32) -> Self {
+            SquareWave {
+                freq,
+                num_sample: 0,
+            }
+        }
+    }
+
+    impl Iterator for SquareWave {
+        type Item = f32;
+
+        fn next(&mut self) -> Option<f32> {
+            self.num_sample = self.num_sample.wrapping_add(1);
+            let t = self.num_sample as f32 / Self::SAMPLE_RATE as f32;
+            Some(if (t * self.freq).fract() < 0.5 {
+                0.5
+            } else {
+                -0.5
+            })
+        }
+    }
+
+    impl Source for SquareWave {
+        fn current_frame_len(&self) -> Option<usize> {
+            None
+        }
+
+        fn channels(&self) -> u16 {
+            1
+        }
+
+        fn sample_rate(&self) -> u32 {
+            Self::SAMPLE_RATE
+        }
+
+        fn total_duration(&self) -> Option<Duration> {
+            None
+        }
+    }
 }
 
 mod render {
 
+    use crate::audio;
     use libchip8::cpu;
     use libchip8::display;
     use libchip8::emulator;
@@ -44,6 +207,24 @@ mod render {
         cpu_width: i32,
         cpu_height: i32,
         kbd_height: i32,
+        fps: u64,
+        instructions_per_frame: u64,
+    }
+
+    impl Config {
+        /// Overrides the target frame rate and/or instructions-per-frame cadence,
+        /// e.g. with values parsed from the CLI; `None` or `Some(0)` keeps the
+        /// current value, since a zero-length frame would panic `chip_loop`'s
+        /// frame-duration division and a zero instruction budget is a no-op.
+        pub fn with_timing(mut self, fps: Option<u64>, instructions_per_frame: Option<u64>) -> Self {
+            if let Some(fps) = fps.filter(|&f| f > 0) {
+                self.fps = fps;
+            }
+            if let Some(ipf) = instructions_per_frame.filter(|&i| i > 0) {
+                self.instructions_per_frame = ipf;
+            }
+            self
+        }
     }
 
     /// Defines a set of xxx_position()
@@ -90,13 +271,149 @@ mod render {
         fn render_dt_st(&mut self, dt_st: (u8, u8));
         fn render_status(&mut self, s: &str);
         fn render_frame(&mut self);
-        fn render_step(&mut self, step: u64, fps: u64);
+        fn render_step(&mut self, step: u64, fps: u64, instr_count: u64, instr_per_sec: u64);
         fn wait_to_quit(&mut self, s: &str);
     }
 
+    /// Live debugger-style state toggled from the keyboard while `chip_loop` runs:
+    /// pause/resume, single-step while paused, and cycles-per-frame speed control.
+    pub(crate) struct Controller {
+        paused: bool,
+        step: bool,
+        step_counter: u64,
+        cycles_per_frame: u64,
+    }
+
+    impl Controller {
+        /// In `RunMode::Stepwise`, `cycles_per_frame` is forced to 1 so that
+        /// each blocking keypress still advances exactly one instruction,
+        /// regardless of the configured/free-running cadence.
+        fn new(cycles_per_frame: u64, rm: RunMode) -> Self {
+            let cycles_per_frame = match rm {
+                RunMode::Stepwise => 1,
+                RunMode::Normal => cycles_per_frame,
+            };
+            Controller {
+                paused: false,
+                step: false,
+                step_counter: 0,
+                cycles_per_frame: cycles_per_frame.max(1),
+            }
+        }
+
+        fn toggle_pause(&mut self) {
+            self.paused = !self.paused;
+        }
+
+        fn request_step(&mut self) {
+            self.step = true;
+        }
+
+        fn speed_up(&mut self) {
+            self.cycles_per_frame += 1;
+        }
+
+        fn speed_down(&mut self) {
+            self.cycles_per_frame = self.cycles_per_frame.saturating_sub(1).max(1);
+        }
+
+        /// How many CHIP-8 instructions `chip_loop` should execute this frame;
+        /// consumes a pending single-step request if paused.
+        fn instructions_this_frame(&mut self) -> u64 {
+            let n = if self.paused {
+                if self.step {
+                    self.step = false;
+                    1
+                } else {
+                    0
+                }
+            } else {
+                self.cycles_per_frame
+            };
+            self.step_counter += n;
+            n
+        }
+
+        fn status(&self) -> String {
+            if self.paused {
+                "PAUSED (',' to quit, '.' to step, 'p' to resume)".to_string()
+            } else {
+                format!(
+                    "RUNNING x{} instr/frame (',' to quit, 'p' to pause)",
+                    self.cycles_per_frame
+                )
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod controller_tests {
+        use super::*;
+
+        #[test]
+        fn running_executes_cycles_per_frame() {
+            let mut c = Controller::new(4, RunMode::Normal);
+            assert_eq!(c.instructions_this_frame(), 4);
+            assert_eq!(c.instructions_this_frame(), 4);
+        }
+
+        #[test]
+        fn paused_executes_nothing_until_stepped() {
+            let mut c = Controller::new(4, RunMode::Normal);
+            c.toggle_pause();
+            assert_eq!(c.instructions_this_frame(), 0);
+            assert_eq!(c.instructions_this_frame(), 0);
+        }
+
+        #[test]
+        fn step_is_consumed_once_then_paused_again() {
+            let mut c = Controller::new(4, RunMode::Normal);
+            c.toggle_pause();
+            c.request_step();
+            assert_eq!(c.instructions_this_frame(), 1);
+            assert_eq!(c.instructions_this_frame(), 0);
+        }
+
+        #[test]
+        fn stepwise_runmode_forces_single_instruction() {
+            let mut c = Controller::new(4, RunMode::Stepwise);
+            assert_eq!(c.instructions_this_frame(), 1);
+        }
+
+        #[test]
+        fn new_clamps_zero_cycles_per_frame_to_one() {
+            let mut c = Controller::new(0, RunMode::Normal);
+            assert_eq!(c.instructions_this_frame(), 1);
+        }
+
+        #[test]
+        fn speed_down_clamps_to_one() {
+            let mut c = Controller::new(1, RunMode::Normal);
+            c.speed_down();
+            assert_eq!(c.instructions_this_frame(), 1);
+        }
+
+        #[test]
+        fn speed_up_increases_cycles_per_frame() {
+            let mut c = Controller::new(1, RunMode::Normal);
+            c.speed_up();
+            assert_eq!(c.instructions_this_frame(), 2);
+        }
+
+        #[test]
+        fn step_counter_accumulates_executed_instructions() {
+            let mut c = Controller::new(3, RunMode::Normal);
+            c.instructions_this_frame();
+            c.instructions_this_frame();
+            assert_eq!(c.step_counter, 6);
+        }
+    }
+
     pub struct EasyCursesRenderer<'s> {
         e: &'s mut EasyCurses,
         cfg: &'s Config,
+        old_display: Option<[bool; display::ROWS * display::COLS]>,
+        controller: Controller,
     }
 
     impl<'s> EasyCursesRenderer<'s> {
@@ -109,13 +426,35 @@ mod render {
                 RunMode::Normal => TimeoutMode::Immediate,
             };
             e.set_input_timeout(tm);
-            EasyCursesRenderer { e, cfg }
+            EasyCursesRenderer {
+                e,
+                cfg,
+                old_display: None,
+                controller: Controller::new(cfg.instructions_per_frame, rm),
+            }
         }
 
         fn refresh(&mut self) {
             self.e.refresh();
         }
 
+        /// How many CHIP-8 instructions `chip_loop` should execute this frame.
+        fn instructions_this_frame(&mut self) -> u64 {
+            self.controller.instructions_this_frame()
+        }
+
+        fn status(&self) -> String {
+            self.controller.status()
+        }
+
+        fn instr_count(&self) -> u64 {
+            self.controller.step_counter
+        }
+
+        fn paused(&self) -> bool {
+            self.controller.paused
+        }
+
         fn handle_input(
             &mut self,
             ch: &mut emulator::Emulator,
@@ -128,6 +467,10 @@ mod render {
                 *last_input = Instant::now();
                 match ip {
                     Input::Character(',') => result = false,
+                    Input::Character('p') => self.controller.toggle_pause(),
+                    Input::Character('.') => self.controller.request_step(),
+                    Input::Character('+') => self.controller.speed_up(),
+                    Input::Character('-') => self.controller.speed_down(),
                     Input::Character(key) => {
                         if let Some(newk) = key_map::map_key_to_base16(key) {
                             ch.key_pressed(oldk.take(), newk);
@@ -195,23 +538,39 @@ mod render {
 
         fn render_display(&mut self, d: &dyn display::Scr) {
             let cfg = &self.cfg;
-            let e = &mut self.e;
             let (r, c) = cfg.display_position();
+
+            let mut new_display = [false; display::ROWS * display::COLS];
+            for y in 0..display::ROWS {
+                for x in 0..display::COLS {
+                    new_display[y * display::COLS + x] = d.get(x, y);
+                }
+            }
+
+            let e = &mut self.e;
             for y in 0i32..display::ROWS as i32 {
                 for x in 0i32..display::COLS as i32 {
-                    let bit = d.get(x as usize, y as usize);
+                    let idx = y as usize * display::COLS + x as usize;
+                    let bit = new_display[idx];
+                    let redraw = match self.old_display {
+                        None => true,
+                        Some(ref old) => old[idx] != bit,
+                    };
+                    if !redraw {
+                        continue;
+                    }
                     let (z, cp) = if bit {
                         (cfg.present, cfg.color_present)
                     } else {
                         (cfg.absent, cfg.color_absent)
                     };
-                    let row = r + y;
-                    let col = c + x;
                     e.set_color_pair(cp);
-                    e.move_rc(row, col);
+                    e.move_rc(r + y, c + x);
                     e.print_char(z);
                 }
             }
+
+            self.old_display = Some(new_display);
         }
 
         fn render_keyboard(&mut self, kbd: &input::Keyboard) {
@@ -276,12 +635,15 @@ mod render {
             e.print_char('>');
         }
 
-        fn render_step(&mut self, step: u64, fps: u64) {
+        fn render_step(&mut self, step: u64, fps: u64, instr_count: u64, instr_per_sec: u64) {
             let cfg = &self.cfg;
             let e = &mut self.e;
             let (r, c) = cfg.step_position();
             e.move_rc(r, c);
-            let s = format!("Frame {}, fps: {}", step, fps);
+            let s = format!(
+                "Frame {}, fps: {}, instr: {} ({}/s)",
+                step, fps, instr_count, instr_per_sec
+            );
             e.print(s);
         }
 
@@ -340,38 +702,76 @@ mod render {
         let mut last_input = Instant::now();
 
         let frame_target_duration = Duration::new(1, 0)
-            .checked_div(120)
+            .checked_div(c.fps as u32)
             .expect("duration division failed");
         let min_press_durarion = Duration::new(1, 0)
             .checked_div(20)
             .expect("min_press_durarion failed");
 
+        // The CHIP-8 delay/sound timers run at a fixed 60 Hz regardless of
+        // render fps or how many instructions execute per frame.
+        let timer_tick_duration = Duration::new(1, 0)
+            .checked_div(60)
+            .expect("duration division failed");
+        let mut next_timer_tick = Instant::now();
+        let mut dt_st = (0u8, 0u8);
+
+        let mut beeper = audio::default_beeper();
+        let mut beeper_on = false;
+
         if let Some(mut e) = EasyCurses::initialize_system() {
             let mut er = EasyCursesRenderer::new(&mut e, c, rm);
             er.render_frame();
-            er.render_status("Press ',' (colon) to stop emulation.");
-            loop {
+            'frame: loop {
                 let top_of_loop = Instant::now();
                 er.render_cpu(&(*ch).cpu);
                 er.render_display((*ch).scr.as_ref());
                 er.render_keyboard(&ch.kbd);
                 if let Some(fps) = step_count.checked_div(start_of_prog.elapsed().as_secs()) {
-                    er.render_step(step_count, fps);
+                    let instr_count = er.instr_count();
+                    let ips = instr_count
+                        .checked_div(start_of_prog.elapsed().as_secs())
+                        .unwrap_or(0);
+                    er.render_step(step_count, fps, instr_count, ips);
                     step_count += 1;
                 }
-                er.render_dt_st(ch.tick());
+                if er.paused() {
+                    // Freeze the timer schedule so no catch-up ticks (and no
+                    // beeper state change) fire once emulation resumes.
+                    next_timer_tick = top_of_loop + timer_tick_duration;
+                } else {
+                    while top_of_loop >= next_timer_tick {
+                        dt_st = ch.tick();
+                        next_timer_tick += timer_tick_duration;
+                    }
+                }
+                let (_, st) = dt_st;
+                let should_beep = st > 0;
+                if should_beep != beeper_on {
+                    if should_beep {
+                        beeper.start();
+                    } else {
+                        beeper.stop();
+                    }
+                    beeper_on = should_beep;
+                }
+                er.render_dt_st(dt_st);
+                let status = er.status();
+                er.render_status(&status);
                 er.refresh();
 
                 if !er.handle_input(ch, &mut last_input, &min_press_durarion, &mut oldk) {
                     break;
                 }
 
-                if let Some(instr) = next_instr {
-                    ch.exec(instr);
-                    next_instr = ch.fetch();
-                } else {
-                    er.render_status("No more instructions to excute");
-                    break;
+                for _ in 0..er.instructions_this_frame() {
+                    if let Some(instr) = next_instr {
+                        ch.exec(instr);
+                        next_instr = ch.fetch();
+                    } else {
+                        er.render_status("No more instructions to excute");
+                        break 'frame;
+                    }
                 }
 
                 let elapsed_this_frame = top_of_loop.elapsed();
@@ -381,18 +781,24 @@ mod render {
                 }
             }
 
+            beeper.stop();
             er.wait_to_quit("Press any key to quit");
         } else {
             println!("Could not initialize easycurses system properly");
         }
     }
 
+    const DEFAULT_PRESENT_FG: Color = Yellow;
+    const DEFAULT_PRESENT_BG: Color = Black;
+    const DEFAULT_ABSENT_FG: Color = Blue;
+    const DEFAULT_ABSENT_BG: Color = Black;
+
     pub fn default_config() -> Config {
         Config {
             present: 'â–ˆ',
             absent: ' ',
-            color_present: ColorPair::new(Yellow, Black),
-            color_absent: ColorPair::new(Blue, Black),
+            color_present: ColorPair::new(DEFAULT_PRESENT_FG, DEFAULT_PRESENT_BG),
+            color_absent: ColorPair::new(DEFAULT_ABSENT_FG, DEFAULT_ABSENT_BG),
             display_width: display::COLS as i32,
             display_height: display::ROWS as i32,
             x0: 3,
@@ -400,38 +806,229 @@ mod render {
             cpu_width: 40,
             cpu_height: 26,
             kbd_height: 5,
+            fps: 60,
+            instructions_per_frame: 8,
+        }
+    }
+
+    /// Raw, optional-everywhere mirror of `Config` deserialized from TOML; every
+    /// field falls back to `default_config()`'s value when absent.
+    #[derive(serde::Deserialize, Default)]
+    struct FileConfig {
+        present: Option<char>,
+        absent: Option<char>,
+        colors: Option<FileColors>,
+        layout: Option<FileLayout>,
+        keymap: Option<String>,
+    }
+
+    #[derive(serde::Deserialize, Default)]
+    struct FileColors {
+        present: Option<FileColorPair>,
+        absent: Option<FileColorPair>,
+    }
+
+    /// Foreground/background color names for one `ColorPair`; either half may
+    /// be omitted and falls back to that slot's default color.
+    #[derive(serde::Deserialize, Default)]
+    struct FileColorPair {
+        fg: Option<String>,
+        bg: Option<String>,
+    }
+
+    #[derive(serde::Deserialize, Default)]
+    struct FileLayout {
+        x0: Option<i32>,
+        y0: Option<i32>,
+        width: Option<i32>,
+        height: Option<i32>,
+    }
+
+    fn parse_color(name: &str) -> Option<Color> {
+        match name.to_lowercase().as_str() {
+            "black" => Some(Black),
+            "red" => Some(Red),
+            "green" => Some(Green),
+            "yellow" => Some(Yellow),
+            "blue" => Some(Blue),
+            "magenta" => Some(Magenta),
+            "cyan" => Some(Cyan),
+            "white" => Some(White),
+            _ => None,
+        }
+    }
+
+    /// Resolves an optional fg/bg color pair from the file, falling back
+    /// independently for each half that's missing or names an unknown color.
+    fn resolve_color_pair(
+        file: Option<FileColorPair>,
+        default_fg: Color,
+        default_bg: Color,
+    ) -> ColorPair {
+        let (fg, bg) = match file {
+            Some(p) => (
+                p.fg.as_deref().and_then(parse_color).unwrap_or(default_fg),
+                p.bg.as_deref().and_then(parse_color).unwrap_or(default_bg),
+            ),
+            None => (default_fg, default_bg),
+        };
+        ColorPair::new(fg, bg)
+    }
+
+    /// Loads `Config` from a TOML file at `path` (default `"chip8.toml"` when
+    /// `None`), applying any overriding `key_map` mapping as a side effect.
+    /// Falls back to `default_config()` wherever the file, a field, or a color
+    /// name can't be read/parsed.
+    pub fn load_config(path: Option<&str>) -> Config {
+        let path = path.unwrap_or("chip8.toml");
+        let cfg = default_config();
+
+        let contents = match std::fs::read_to_string(path) {
+            Ok(s) => s,
+            Err(_) => return cfg,
+        };
+        let file_cfg: FileConfig = match toml::from_str(&contents) {
+            Ok(c) => c,
+            Err(e) => {
+                eprintln!("Ignoring config file {}: {}", path, e);
+                return cfg;
+            }
+        };
+
+        if let Some(keymap) = file_cfg.keymap {
+            if let Err(e) = key_map::set_mapping(keymap) {
+                eprintln!("Ignoring keymap in {}: {}", path, e);
+            }
+        }
+
+        let (color_present, color_absent) = match file_cfg.colors {
+            Some(colors) => (
+                resolve_color_pair(colors.present, DEFAULT_PRESENT_FG, DEFAULT_PRESENT_BG),
+                resolve_color_pair(colors.absent, DEFAULT_ABSENT_FG, DEFAULT_ABSENT_BG),
+            ),
+            None => (cfg.color_present, cfg.color_absent),
+        };
+
+        let (x0, y0, display_width, display_height) = match file_cfg.layout {
+            Some(layout) => (
+                layout.x0.unwrap_or(cfg.x0),
+                layout.y0.unwrap_or(cfg.y0),
+                layout.width.unwrap_or(cfg.display_width),
+                layout.height.unwrap_or(cfg.display_height),
+            ),
+            None => (cfg.x0, cfg.y0, cfg.display_width, cfg.display_height),
+        };
+
+        Config {
+            present: file_cfg.present.unwrap_or(cfg.present),
+            absent: file_cfg.absent.unwrap_or(cfg.absent),
+            color_present,
+            color_absent,
+            display_width,
+            display_height,
+            x0,
+            y0,
+            ..cfg
+        }
+    }
+
+    #[cfg(test)]
+    mod file_config_tests {
+        use super::*;
+
+        #[test]
+        fn parse_color_is_case_insensitive() {
+            assert_eq!(parse_color("YELLOW"), Some(Yellow));
+        }
+
+        #[test]
+        fn parse_color_rejects_unknown_name() {
+            assert_eq!(parse_color("chartreuse"), None);
+        }
+
+        #[test]
+        fn resolve_color_pair_uses_defaults_when_absent() {
+            let resolved = resolve_color_pair(None, Yellow, Black);
+            assert_eq!(resolved, ColorPair::new(Yellow, Black));
+        }
+
+        #[test]
+        fn resolve_color_pair_falls_back_per_channel() {
+            let file = FileColorPair {
+                fg: Some("red".to_string()),
+                bg: None,
+            };
+            let resolved = resolve_color_pair(Some(file), Yellow, Black);
+            assert_eq!(resolved, ColorPair::new(Red, Black));
+        }
+
+        #[test]
+        fn resolve_color_pair_ignores_unknown_color_name() {
+            let file = FileColorPair {
+                fg: Some("chartreuse".to_string()),
+                bg: None,
+            };
+            let resolved = resolve_color_pair(Some(file), Yellow, Black);
+            assert_eq!(resolved, ColorPair::new(Yellow, Black));
         }
     }
 }
 
 mod mode {
-    #[derive(Debug, PartialEq)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
     pub(crate) enum RunMode {
         Stepwise,
         Normal,
     }
+}
 
-    impl std::convert::From<Option<&String>> for RunMode {
-        fn from(s: Option<&String>) -> Self {
-            match s {
-                None => RunMode::Normal,
-                Some(_) => RunMode::Stepwise,
-            }
-        }
+mod cli {
+    use clap::{Parser, Subcommand};
+
+    #[derive(Parser)]
+    #[command(name = "rust-chip8", about = "A CHIP-8 emulator")]
+    pub(crate) struct Cli {
+        #[command(subcommand)]
+        pub(crate) command: Command,
     }
-}
-mod run {
 
-    use crate::mode::RunMode;
-    use crate::render;
-    use libchip8::emulator;
-    use libchip8::loader;
+    #[derive(Subcommand)]
+    pub(crate) enum Command {
+        /// Load and run a ROM file
+        Rom {
+            /// Path to the ROM file to load
+            path: String,
 
-    pub(crate) fn emulation(ch: &mut emulator::Emulator, fname: &str, runmode: RunMode) {
-        loader::load(ch, &String::from(fname));
-        ch.store_font();
+            /// Execute exactly one instruction per keypress, ignoring --ipf
+            #[arg(long, conflicts_with = "normal")]
+            stepwise: bool,
 
-        render::chip_loop(ch, &render::default_config(), runmode);
+            /// Run continuously at the configured frame/instruction rate (default)
+            #[arg(long, conflicts_with = "stepwise")]
+            normal: bool,
+
+            /// Target frames per second for the display (must be at least 1)
+            #[arg(long, value_parser = parse_nonzero)]
+            fps: Option<u64>,
+
+            /// CHIP-8 instructions executed per rendered frame (must be at least 1)
+            #[arg(long = "ipf", value_parser = parse_nonzero)]
+            instructions_per_frame: Option<u64>,
+
+            /// Path to a TOML config file (defaults to "chip8.toml")
+            #[arg(long)]
+            config: Option<String>,
+        },
+    }
+
+    /// Rejects 0, which would make the frame/instruction cadence in `chip_loop`
+    /// meaningless (a zero-length frame or a no-op instruction budget).
+    fn parse_nonzero(s: &str) -> Result<u64, String> {
+        match s.parse::<u64>() {
+            Ok(0) => Err("must be at least 1".to_string()),
+            Ok(n) => Ok(n),
+            Err(e) => Err(e.to_string()),
+        }
     }
 
     #[cfg(test)]
@@ -439,28 +1036,65 @@ mod run {
         use super::*;
 
         #[test]
-        fn from_none_test() {
-            assert_eq!(RunMode::Normal, RunMode::from(None));
+        fn parse_nonzero_rejects_zero() {
+            assert!(parse_nonzero("0").is_err());
         }
 
         #[test]
-        fn from_some_test() {
-            assert_eq!(RunMode::Stepwise, RunMode::from(Some(&String::from("-s"))));
+        fn parse_nonzero_accepts_positive() {
+            assert_eq!(parse_nonzero("60"), Ok(60));
         }
+
+        #[test]
+        fn parse_nonzero_rejects_non_numeric() {
+            assert!(parse_nonzero("not-a-number").is_err());
+        }
+    }
+}
+
+mod run {
+
+    use crate::mode::RunMode;
+    use crate::render;
+    use libchip8::emulator;
+    use libchip8::loader;
+
+    pub(crate) fn emulation(
+        ch: &mut emulator::Emulator,
+        fname: &str,
+        runmode: RunMode,
+        cfg: &render::Config,
+    ) {
+        loader::load(ch, &String::from(fname));
+        ch.store_font();
+
+        render::chip_loop(ch, cfg, runmode);
     }
 }
 
+use clap::Parser;
+use cli::Command;
 use libchip8::emulator;
 use mode::RunMode;
-use std::env;
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    if let Some(fname) = &args.get(1) {
-        let mut emulator = emulator::Emulator::new();
-        let runmode: RunMode = RunMode::from(args.get(2));
-        run::emulation(&mut emulator, fname, runmode);
+    let cli = cli::Cli::parse();
+    let Command::Rom {
+        path,
+        stepwise,
+        normal: _,
+        fps,
+        instructions_per_frame,
+        config,
+    } = cli.command;
+
+    let runmode = if stepwise {
+        RunMode::Stepwise
     } else {
-        println!("chip-8 rom file name required");
-    }
+        RunMode::Normal
+    };
+    let cfg = render::load_config(config.as_deref()).with_timing(fps, instructions_per_frame);
+
+    let mut emulator = emulator::Emulator::new();
+    run::emulation(&mut emulator, &path, runmode, &cfg);
 }